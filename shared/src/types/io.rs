@@ -2,26 +2,58 @@
 //! generic IO. The defaults are the obvious Rust native
 //! functions.
 
+use std::cell::RefCell;
+use std::io::{BufRead, IsTerminal};
+
+thread_local! {
+    /// A buffered handle onto stdin, kept alive for the lifetime of the
+    /// thread so that repeated line reads share one buffer instead of
+    /// allocating a fresh reader on every call. Stdin itself is not held
+    /// locked; it is locked again each time the buffer refills.
+    static STDIN: RefCell<std::io::BufReader<std::io::Stdin>> =
+        RefCell::new(std::io::BufReader::new(std::io::stdin()));
+}
+
 /// Rust native I/O handling.
 pub struct DefaultIo;
 
-impl Io for DefaultIo {}
+impl Io for DefaultIo {
+    /// Skips the `String::from_utf8_lossy` detour `print` would take.
+    fn print_bytes(bytes: &[u8]) -> std::io::Result<()> {
+        Self::write_all(std::io::stdout(), bytes)
+    }
+
+    fn println_bytes(bytes: &[u8]) -> std::io::Result<()> {
+        Self::write_all(std::io::stdout(), bytes)?;
+        Self::write_all(std::io::stdout(), b"\n")
+    }
+
+    fn eprintln_bytes(bytes: &[u8]) -> std::io::Result<()> {
+        Self::write_all(std::io::stderr(), bytes)?;
+        Self::write_all(std::io::stderr(), b"\n")
+    }
+}
 
-#[allow(missing_docs)]
+/// Abstraction over the program's input/output, so commands can run
+/// against real stdio or an in-memory double in tests.
 pub trait Io {
-    fn print(output: impl AsRef<str>) {
-        print!("{}", output.as_ref());
+    /// Write `output` to stdout, with no trailing newline.
+    fn print(output: impl AsRef<str>) -> std::io::Result<()> {
+        Self::write(std::io::stdout(), output)
     }
 
-    fn flush() {
+    /// Flush stdout.
+    fn flush() -> std::io::Result<()> {
         use std::io::Write;
-        std::io::stdout().flush().unwrap();
+        std::io::stdout().flush()
     }
 
-    fn println(output: impl AsRef<str>) {
-        println!("{}", output.as_ref());
+    /// Write `output` to stdout, followed by a newline.
+    fn println(output: impl AsRef<str>) -> std::io::Result<()> {
+        Self::writeln(std::io::stdout(), output)
     }
 
+    /// Write `output` to `writer`, with no trailing newline.
     fn write<W: std::io::Write>(
         mut writer: W,
         output: impl AsRef<str>,
@@ -29,6 +61,7 @@ pub trait Io {
         write!(writer, "{}", output.as_ref())
     }
 
+    /// Write `output` to `writer`, followed by a newline.
     fn writeln<W: std::io::Write>(
         mut writer: W,
         output: impl AsRef<str>,
@@ -36,45 +69,536 @@ pub trait Io {
         writeln!(writer, "{}", output.as_ref())
     }
 
-    fn eprintln(output: impl AsRef<str>) {
-        eprintln!("{}", output.as_ref());
+    /// Write `output` to stderr, followed by a newline.
+    fn eprintln(output: impl AsRef<str>) -> std::io::Result<()> {
+        Self::writeln(std::io::stderr(), output)
+    }
+
+    /// Write raw bytes to `writer` directly, skipping `format_args`.
+    /// Used by [`display!`]/[`display_line!`] for plain literals.
+    fn write_all<W: std::io::Write>(
+        mut writer: W,
+        bytes: &[u8],
+    ) -> std::io::Result<()> {
+        writer.write_all(bytes)
+    }
+
+    /// The [`display!`] fast path for a plain literal: `bytes` with no
+    /// trailing newline, routed through [`Io::print`] by default so an
+    /// override still sees it.
+    fn print_bytes(bytes: &[u8]) -> std::io::Result<()> {
+        Self::print(String::from_utf8_lossy(bytes))
+    }
+
+    /// The [`display_line!`] fast path, mirroring [`Io::print_bytes`]
+    /// but routed through [`Io::println`].
+    fn println_bytes(bytes: &[u8]) -> std::io::Result<()> {
+        Self::println(String::from_utf8_lossy(bytes))
+    }
+
+    /// The [`edisplay!`] fast path, mirroring [`Io::print_bytes`] but
+    /// routed through [`Io::eprintln`].
+    fn eprintln_bytes(bytes: &[u8]) -> std::io::Result<()> {
+        Self::eprintln(String::from_utf8_lossy(bytes))
     }
 
+    /// Read everything remaining on stdin to a `String`, via the same
+    /// buffered handle as [`Io::read_line`]/[`Io::lines`].
     fn read() -> std::io::Result<String> {
-        read_aux(std::io::stdin().lock())
+        use std::io::Read;
+        STDIN.with(|stdin| {
+            let mut s = String::new();
+            stdin.borrow_mut().read_to_string(&mut s)?;
+            Ok(s)
+        })
     }
 
-    fn prompt(question: impl AsRef<str>) -> String {
-        prompt_aux(
-            std::io::stdin().lock(),
-            std::io::stdout(),
-            question.as_ref(),
-        )
+    /// Read a single line of input into `buf`, including the trailing
+    /// `\n` (or `\r\n`), and return the number of bytes read. A return
+    /// value of `0` signals EOF, mirroring [`BufRead::read_line`].
+    fn read_line(buf: &mut String) -> std::io::Result<usize> {
+        STDIN.with(|stdin| stdin.borrow_mut().read_line(buf))
+    }
+
+    /// Like [`Io::read_line`], but strips a single trailing `\n` or
+    /// `\r\n` line ending from the returned line, if present.
+    fn read_line_trimmed() -> std::io::Result<String> {
+        let mut buf = String::new();
+        Self::read_line(&mut buf)?;
+        strip_line_ending(&mut buf);
+        Ok(buf)
+    }
+
+    /// An iterator over the lines of input, read one at a time from the
+    /// same buffered source as [`Io::read_line`].
+    fn lines() -> Lines<Self>
+    where
+        Self: Sized,
+    {
+        Lines(std::marker::PhantomData)
+    }
+
+    /// Display `question` then read everything remaining on stdin, via
+    /// the same buffered handle as [`Io::read`].
+    fn prompt(question: impl AsRef<str>) -> std::io::Result<String> {
+        Self::print(question)?;
+        Self::flush()?;
+        Self::read()
+    }
+
+    /// Wrap this `Io` implementation's standard output in a
+    /// block-buffered [`BufferedIo`], so that subsequent writes
+    /// accumulate in memory and only reach the syscall boundary when the
+    /// buffer fills, on an explicit [`BufferedIo::flush`], or when the
+    /// wrapper is dropped.
+    fn buffered() -> BufferedIo<Self>
+    where
+        Self: Sized,
+    {
+        BufferedIo::new()
+    }
+
+    /// Display `question` and read a line with terminal echo disabled,
+    /// so passwords and mnemonics aren't printed back to the screen.
+    /// Falls back to a plain [`Io::read_line_trimmed`] when stdin isn't
+    /// a terminal (e.g. piped input).
+    fn read_password(question: impl AsRef<str>) -> std::io::Result<Zeroizing> {
+        Self::print(question)?;
+        Self::flush()?;
+        if std::io::stdin().is_terminal() {
+            set_terminal_echo(false)?;
+            let secret = read_secret_line().map(Zeroizing::new);
+            // Always try to restore echo, even if the read above
+            // failed, so a read error doesn't leave the terminal
+            // stuck in a silent, no-echo state.
+            let restore = set_terminal_echo(true);
+            // The newline from the user's keypress was never echoed
+            // back; print one so subsequent output doesn't land on
+            // the same line as the prompt.
+            Self::println("")?;
+            let secret = secret?;
+            restore?;
+            return Ok(secret);
+        }
+        Ok(Zeroizing::new(Self::read_line_trimmed()?))
+    }
+
+    /// Display `question`, read a line of input and attempt to parse it
+    /// as `T`. On a parse failure, the prompt is re-displayed along with
+    /// the parse error, up to `max_attempts` times (or until EOF).
+    fn prompt_parse<T>(
+        question: impl AsRef<str>,
+        max_attempts: usize,
+    ) -> Result<T, PromptError<T::Err>>
+    where
+        Self: Sized,
+        T: std::str::FromStr,
+        T::Err: std::fmt::Display,
+    {
+        Self::prompt_validated(question, max_attempts, |input| input.parse::<T>())
+    }
+
+    /// Display `question`, read a line of input and hand it to
+    /// `validate`. On a validation failure, the prompt is re-displayed
+    /// along with the returned error message, up to `max_attempts` times
+    /// (or until EOF).
+    fn prompt_validated<T, E>(
+        question: impl AsRef<str>,
+        max_attempts: usize,
+        mut validate: impl FnMut(&str) -> Result<T, E>,
+    ) -> Result<T, PromptError<E>>
+    where
+        Self: Sized,
+        E: std::fmt::Display,
+    {
+        let question = question.as_ref();
+        let mut last_err = None;
+        for _ in 0..max_attempts.max(1) {
+            Self::print(question)?;
+            Self::flush()?;
+            let mut buf = String::new();
+            if Self::read_line(&mut buf)? == 0 {
+                return Err(PromptError::Eof);
+            }
+            strip_line_ending(&mut buf);
+            match validate(&buf) {
+                Ok(value) => return Ok(value),
+                Err(e) => {
+                    Self::println(format!("Invalid input: {e}"))?;
+                    last_err = Some(e);
+                }
+            }
+        }
+        Err(PromptError::MaxAttempts(
+            last_err.expect("at least one attempt is made, so an error was recorded"),
+        ))
+    }
+}
+
+/// Errors that can occur while obtaining typed input via
+/// [`Io::prompt_parse`] or [`Io::prompt_validated`].
+#[derive(Debug)]
+pub enum PromptError<E> {
+    /// An I/O error occurred while reading from or writing to the
+    /// terminal.
+    Io(std::io::Error),
+    /// Input was exhausted (EOF) before a valid value was supplied.
+    Eof,
+    /// `max_attempts` were exhausted; carries the last validation error.
+    MaxAttempts(E),
+}
+
+impl<E> From<std::io::Error> for PromptError<E> {
+    fn from(err: std::io::Error) -> Self {
+        Self::Io(err)
+    }
+}
+
+impl<E: std::fmt::Display> std::fmt::Display for PromptError<E> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Io(e) => write!(f, "I/O error: {e}"),
+            Self::Eof => write!(f, "input ended before a valid value was entered"),
+            Self::MaxAttempts(e) => {
+                write!(f, "maximum number of attempts exceeded: {e}")
+            }
+        }
+    }
+}
+
+impl<E: std::fmt::Debug + std::fmt::Display> std::error::Error for PromptError<E> {}
+
+/// A `String` that is zeroed on drop, so secrets read via
+/// [`Io::read_password`] don't linger in freed memory. A minimal
+/// in-house stand-in for the `zeroize` crate, scoped to just this field.
+pub struct Zeroizing(String);
+
+impl Zeroizing {
+    fn new(secret: String) -> Self {
+        Self(secret)
+    }
+}
+
+impl std::ops::Deref for Zeroizing {
+    type Target = str;
+
+    fn deref(&self) -> &str {
+        &self.0
+    }
+}
+
+impl std::fmt::Debug for Zeroizing {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str("Zeroizing(...)")
+    }
+}
+
+impl Drop for Zeroizing {
+    fn drop(&mut self) {
+        // SAFETY: every byte is overwritten with the zero byte, which
+        // is itself valid UTF-8, before the buffer is deallocated; the
+        // transient invalid state in between is never observed.
+        unsafe {
+            for byte in self.0.as_bytes_mut() {
+                std::ptr::write_volatile(byte, 0);
+            }
+        }
+    }
+}
+
+/// Toggle the controlling terminal's echo flag on/off via `stty`,
+/// avoiding a `termios`/`libc` dependency for it. Used by
+/// [`Io::read_password`]; a non-zero exit is reported as an error
+/// rather than silently leaving echo untouched.
+#[cfg(unix)]
+fn set_terminal_echo(enable: bool) -> std::io::Result<()> {
+    let flag = if enable { "echo" } else { "-echo" };
+    let status = std::process::Command::new("stty")
+        .arg(flag)
+        .stdin(std::process::Stdio::inherit())
+        .status()?;
+    if !status.success() {
+        return Err(std::io::Error::other(format!(
+            "`stty {flag}` exited with {status}"
+        )));
+    }
+    Ok(())
+}
+
+/// Toggle the console's echo flag on/off via the Win32 console API,
+/// mirroring the Unix `stty` implementation above without a
+/// `winapi`/`windows-sys` dependency for two function calls.
+#[cfg(windows)]
+fn set_terminal_echo(enable: bool) -> std::io::Result<()> {
+    use std::os::windows::io::{AsRawHandle, RawHandle};
+
+    const ENABLE_ECHO_INPUT: u32 = 0x0004;
+
+    #[link(name = "kernel32")]
+    extern "system" {
+        fn GetConsoleMode(console_handle: RawHandle, mode: *mut u32) -> i32;
+        fn SetConsoleMode(console_handle: RawHandle, mode: u32) -> i32;
+    }
+
+    let handle = std::io::stdin().as_raw_handle();
+    let mut mode: u32 = 0;
+    // SAFETY: `handle` is the console's own stdin handle, valid for the
+    // duration of the process; `mode` is a valid, properly-aligned
+    // pointer to a local on this thread's stack.
+    if unsafe { GetConsoleMode(handle, &mut mode) } == 0 {
+        return Err(std::io::Error::last_os_error());
+    }
+    let mode = if enable {
+        mode | ENABLE_ECHO_INPUT
+    } else {
+        mode & !ENABLE_ECHO_INPUT
+    };
+    // SAFETY: same handle as the `GetConsoleMode` call above.
+    if unsafe { SetConsoleMode(handle, mode) } == 0 {
+        return Err(std::io::Error::last_os_error());
+    }
+    Ok(())
+}
+
+/// Fallback for platforms with neither `stty` nor the Win32 console API
+/// available. Reports the operation as unsupported rather than quietly
+/// leaving echo enabled, so [`Io::read_password`] errors out instead of
+/// reading a secret in the clear.
+#[cfg(not(any(unix, windows)))]
+fn set_terminal_echo(_enable: bool) -> std::io::Result<()> {
+    Err(std::io::Error::new(
+        std::io::ErrorKind::Unsupported,
+        "disabling terminal echo is not supported on this platform",
+    ))
+}
+
+/// Read a single line directly off a freshly-locked stdin, bypassing the
+/// shared, thread-lifetime [`STDIN`] buffer so a secret's bytes are
+/// never copied into it. The buffer read into here is zeroed before
+/// being freed, on both the success and the invalid-UTF-8 path.
+fn read_secret_line() -> std::io::Result<String> {
+    use std::io::Read;
+
+    let mut stdin = std::io::stdin().lock();
+    // Pre-reserve a generous capacity so a multi-word mnemonic doesn't
+    // reallocate mid-read, leaving unzeroed partial copies on the heap.
+    let mut raw = Vec::with_capacity(256);
+    let mut byte = [0u8; 1];
+    loop {
+        if stdin.read(&mut byte)? == 0 {
+            break;
+        }
+        if byte[0] == b'\n' {
+            break;
+        }
+        raw.push(byte[0]);
+    }
+    if raw.last() == Some(&b'\r') {
+        raw.pop();
+    }
+    match String::from_utf8(raw) {
+        Ok(secret) => Ok(secret),
+        Err(err) => {
+            let mut bytes = err.into_bytes();
+            // SAFETY: every byte is overwritten with the zero byte
+            // before `bytes` is dropped; nothing reads `bytes` again
+            // afterwards, so the brief all-zero state is never
+            // observed and need not be valid UTF-8.
+            unsafe {
+                for byte in bytes.iter_mut() {
+                    std::ptr::write_volatile(byte, 0);
+                }
+            }
+            Err(std::io::Error::new(
+                std::io::ErrorKind::InvalidData,
+                "secret input was not valid UTF-8",
+            ))
+        }
+    }
+}
+
+/// Strip a single trailing line ending (`\n`, or `\r\n`) from `buf`, in
+/// place. Unlike `trim_end_matches(['\n', '\r'])`, this removes at most
+/// one line terminator, so a line that genuinely ends in a literal
+/// `\r` with no following `\n` (e.g. the last line before EOF) is left
+/// alone instead of having that `\r` stripped too.
+fn strip_line_ending(buf: &mut String) {
+    if buf.ends_with('\n') {
+        buf.pop();
+        if buf.ends_with('\r') {
+            buf.pop();
+        }
+    }
+}
+
+/// An iterator over lines of input read via [`Io::read_line`],
+/// mirroring [`std::io::Lines`].
+pub struct Lines<IO>(std::marker::PhantomData<IO>);
+
+impl<IO: Io> Iterator for Lines<IO> {
+    type Item = std::io::Result<String>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let mut buf = String::new();
+        match IO::read_line(&mut buf) {
+            Ok(0) => None,
+            Ok(_) => {
+                strip_line_ending(&mut buf);
+                Some(Ok(buf))
+            }
+            Err(e) => Some(Err(e)),
+        }
+    }
+}
+
+/// Whether a [`BufferedIo`] flushes after every newline (matching
+/// [`std::io::LineWriter`]) or only once its block buffer fills up or is
+/// explicitly flushed (matching [`std::io::BufWriter`]).
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum BufferMode {
+    /// Flush whenever a newline is written; keeps chatty, interactive
+    /// output timely while still batching the writes that make up a
+    /// single line.
+    LineBuffered,
+    /// Only flush once the buffer fills, or when asked; the best
+    /// throughput for large volumes of output where latency doesn't
+    /// matter.
+    BlockBuffered,
+}
+
+enum BufferedSink<W: std::io::Write> {
+    Line(std::io::LineWriter<W>),
+    Block(std::io::BufWriter<W>),
+}
+
+impl<W: std::io::Write> std::io::Write for BufferedSink<W> {
+    fn write(&mut self, buf: &[u8]) -> std::io::Result<usize> {
+        match self {
+            Self::Line(w) => w.write(buf),
+            Self::Block(w) => w.write(buf),
+        }
+    }
+
+    fn flush(&mut self) -> std::io::Result<()> {
+        match self {
+            Self::Line(w) => w.flush(),
+            Self::Block(w) => w.flush(),
+        }
     }
 }
 
-/// A generic function for displaying a prompt to users and reading
-/// in their response.
-pub fn prompt_aux<R, W>(mut reader: R, mut writer: W, question: &str) -> String
-where
-    R: std::io::Read,
-    W: std::io::Write,
-{
-    write!(&mut writer, "{}", question).expect("Unable to write");
-    writer.flush().unwrap();
-    let mut s = String::new();
-    reader.read_to_string(&mut s).expect("Unable to read");
-    s
+/// A buffering wrapper around a writer, generic over the sink (`W`,
+/// defaulting to stdout) so it can be driven against an in-memory
+/// buffer in tests the same way the rest of this module is. Writes made
+/// through [`BufferedIo::print`]/[`BufferedIo::println`] accumulate in
+/// memory instead of issuing a syscall per call, the way a bare
+/// [`DefaultIo::println`] does. Call [`BufferedIo::flush`] at a safe
+/// point to push accumulated output out; the buffer is also flushed on
+/// drop so nothing written is ever lost.
+pub struct BufferedIo<I, W: std::io::Write = std::io::Stdout> {
+    sink: BufferedSink<W>,
+    _io: std::marker::PhantomData<I>,
 }
 
-/// A generic function for reading input from users
-pub fn read_aux<R>(mut reader: R) -> std::io::Result<String>
-where
-    R: std::io::Read,
-{
-    let mut s = String::new();
-    reader.read_to_string(&mut s)?;
-    Ok(s)
+impl<I: Io> BufferedIo<I> {
+    /// Construct a block-buffered wrapper onto stdout (the default
+    /// `BufWriter` capacity). Use [`BufferedIo::with_mode`] for
+    /// line-buffered behavior, or [`BufferedIo::with_writer`] for a
+    /// sink other than stdout.
+    pub fn new() -> Self {
+        Self::with_mode(BufferMode::BlockBuffered)
+    }
+
+    /// Construct a wrapper onto stdout with the given buffering
+    /// strategy.
+    pub fn with_mode(mode: BufferMode) -> Self {
+        Self::with_writer(mode, std::io::stdout())
+    }
+}
+
+impl<I: Io, W: std::io::Write> BufferedIo<I, W> {
+    /// Construct a wrapper with the given buffering strategy around an
+    /// arbitrary writer.
+    pub fn with_writer(mode: BufferMode, writer: W) -> Self {
+        let sink = match mode {
+            BufferMode::LineBuffered => BufferedSink::Line(std::io::LineWriter::new(writer)),
+            BufferMode::BlockBuffered => BufferedSink::Block(std::io::BufWriter::new(writer)),
+        };
+        Self {
+            sink,
+            _io: std::marker::PhantomData,
+        }
+    }
+
+    /// Buffer `output`, with no trailing newline. Routed through
+    /// [`Io::write`] so that an [`Io`] impl overriding its formatting
+    /// behavior is still respected by the buffered path.
+    pub fn print(&mut self, output: impl AsRef<str>) -> std::io::Result<()> {
+        I::write(&mut self.sink, output)
+    }
+
+    /// Buffer `output`, followed by a newline. Routed through
+    /// [`Io::writeln`], for the same reason as [`BufferedIo::print`].
+    pub fn println(&mut self, output: impl AsRef<str>) -> std::io::Result<()> {
+        I::writeln(&mut self.sink, output)
+    }
+
+    /// Buffer raw bytes directly, bypassing the formatting machinery
+    /// the way the [`display!`]/[`display_line!`] literal arms do;
+    /// routed through [`Io::write_all`].
+    pub fn write_bytes(&mut self, bytes: &[u8]) -> std::io::Result<()> {
+        I::write_all(&mut self.sink, bytes)
+    }
+
+    /// Push any buffered output out to the underlying writer.
+    pub fn flush(&mut self) -> std::io::Result<()> {
+        use std::io::Write;
+        self.sink.flush()
+    }
+}
+
+impl<I: Io> Default for BufferedIo<I> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<I, W: std::io::Write> Drop for BufferedIo<I, W> {
+    fn drop(&mut self) {
+        use std::io::Write;
+        // Best-effort: there's nowhere to surface an error from a drop.
+        let _ = self.sink.flush();
+    }
+}
+
+impl<I, W: std::io::Write> std::io::Write for BufferedIo<I, W> {
+    fn write(&mut self, buf: &[u8]) -> std::io::Result<usize> {
+        std::io::Write::write(&mut self.sink, buf)
+    }
+
+    fn flush(&mut self) -> std::io::Result<()> {
+        std::io::Write::flush(&mut self.sink)
+    }
+}
+
+/// Whether `s` contains a raw `{` or `}`, i.e. could be a `format_args!`
+/// interpolation point rather than a plain literal. Used by the
+/// `display!`/`display_line!`/`edisplay!` literal arms to decide between
+/// the allocation-free `write_all` fast path and going through
+/// `format_args!`, so a literal like `"{unbalanced"` or `"{var}"` still
+/// gets the usual compile-time format-string check instead of being
+/// printed verbatim.
+#[doc(hidden)]
+pub const fn literal_needs_formatting(s: &str) -> bool {
+    let bytes = s.as_bytes();
+    let mut i = 0;
+    while i < bytes.len() {
+        if bytes[i] == b'{' || bytes[i] == b'}' {
+            return true;
+        }
+        i += 1;
+    }
+    false
 }
 
 /// Convenience macro for formatting arguments to
@@ -84,9 +608,23 @@ macro_rules! display {
     ($io:ty) => {
       <$io>::print("")
     };
+    ($io:ty, $w:expr; $lit:literal) => {
+        if $crate::types::io::literal_needs_formatting($lit) {
+            <$io>::write($w, format_args!($lit).to_string())
+        } else {
+            <$io>::write_all($w, $lit.as_bytes())
+        }
+    };
     ($io:ty, $w:expr; $($args:tt)*) => {
         <$io>::write($w, format_args!($($args)*).to_string())
     };
+    ($io:ty, $lit:literal) => {
+        if $crate::types::io::literal_needs_formatting($lit) {
+            <$io>::print(format_args!($lit).to_string())
+        } else {
+            <$io>::print_bytes($lit.as_bytes())
+        }
+    };
     ($io:ty,$($args:tt)*) => {
         <$io>::print(format_args!($($args)*).to_string())
     };
@@ -99,9 +637,23 @@ macro_rules! display_line {
     ($io:ty) => {
       <$io>::println("")
     };
+    ($io:ty, $w:expr; $lit:literal) => {
+        if $crate::types::io::literal_needs_formatting($lit) {
+            <$io>::writeln($w, format_args!($lit).to_string())
+        } else {
+            <$io>::write_all($w, concat!($lit, "\n").as_bytes())
+        }
+    };
     ($io:ty, $w:expr; $($args:tt)*) => {
         <$io>::writeln($w, format_args!($($args)*).to_string())
     };
+    ($io:ty, $lit:literal) => {
+        if $crate::types::io::literal_needs_formatting($lit) {
+            <$io>::println(format_args!($lit).to_string())
+        } else {
+            <$io>::println_bytes($lit.as_bytes())
+        }
+    };
     ($io:ty,$($args:tt)*) => {
         <$io>::println(format_args!($($args)*).to_string())
     };
@@ -111,6 +663,13 @@ macro_rules! display_line {
 /// [`Io::eprintln`]
 #[macro_export]
 macro_rules! edisplay {
+    ($io:ty, $lit:literal) => {
+        if $crate::types::io::literal_needs_formatting($lit) {
+            <$io>::eprintln(format_args!($lit).to_string())
+        } else {
+            <$io>::eprintln_bytes($lit.as_bytes())
+        }
+    };
     ($io:ty,$($args:tt)*) => {
         <$io>::eprintln(format_args!($($args)*).to_string())
     };
@@ -124,3 +683,244 @@ macro_rules! prompt {
         <$io>::prompt(format!("{}", format_args!($($arg)*)))
     }}
 }
+
+#[macro_export]
+/// A convenience macro for formatting the user prompt before
+/// forwarding it to the [`Io::read_password`] method.
+macro_rules! prompt_secret {
+    ($io:ty,$($arg:tt)*) => {{
+        <$io>::read_password(format!("{}", format_args!($($arg)*)))
+    }}
+}
+
+/// The default number of attempts made by [`prompt_parse!`] before
+/// giving up on typed input.
+pub const DEFAULT_PROMPT_ATTEMPTS: usize = 3;
+
+#[macro_export]
+/// A convenience macro for formatting a prompt and parsing the user's
+/// response as a given type, re-prompting on failure. Forwards to
+/// [`Io::prompt_parse`] with [`DEFAULT_PROMPT_ATTEMPTS`]; to override
+/// it, pass an explicit attempt count after the type, followed by `;`
+/// and then the prompt (`prompt_parse!(io, T, 5; "Amount > ")`).
+macro_rules! prompt_parse {
+    ($io:ty, $t:ty, $max_attempts:expr; $($arg:tt)*) => {{
+        <$io>::prompt_parse::<$t>(
+            format!("{}", format_args!($($arg)*)),
+            $max_attempts,
+        )
+    }};
+    ($io:ty, $t:ty, $($arg:tt)*) => {{
+        <$io>::prompt_parse::<$t>(
+            format!("{}", format_args!($($arg)*)),
+            $crate::types::io::DEFAULT_PROMPT_ATTEMPTS,
+        )
+    }};
+}
+
+#[cfg(test)]
+mod tests {
+    use std::cell::RefCell;
+    use std::collections::VecDeque;
+
+    use super::{BufferMode, BufferedIo, Io, PromptError};
+
+    thread_local! {
+        // Queued lines (each including its own line terminator, if any)
+        // that `TestIo::read_line` hands out in order.
+        static INPUT: RefCell<VecDeque<String>> = const { RefCell::new(VecDeque::new()) };
+        // Every string `TestIo::print`/`TestIo::println` were called
+        // with, in order, instead of ever touching real stdout.
+        static OUTPUT: RefCell<Vec<String>> = const { RefCell::new(Vec::new()) };
+    }
+
+    /// Feed `lines` (verbatim, including any line terminator) to the
+    /// next calls to `TestIo::read_line`/`read_line_trimmed`/`lines`.
+    fn set_input(lines: &[&str]) {
+        INPUT.with(|input| {
+            *input.borrow_mut() = lines.iter().map(|s| s.to_string()).collect();
+        });
+    }
+
+    fn take_output() -> Vec<String> {
+        OUTPUT.with(|output| std::mem::take(&mut *output.borrow_mut()))
+    }
+
+    /// An in-memory `Io` for exercising the read-side control flow
+    /// without touching the real stdin/stdout.
+    struct TestIo;
+
+    impl Io for TestIo {
+        fn print(output: impl AsRef<str>) -> std::io::Result<()> {
+            OUTPUT.with(|o| o.borrow_mut().push(output.as_ref().to_string()));
+            Ok(())
+        }
+
+        fn println(output: impl AsRef<str>) -> std::io::Result<()> {
+            Self::print(output)
+        }
+
+        fn flush() -> std::io::Result<()> {
+            Ok(())
+        }
+
+        fn read_line(buf: &mut String) -> std::io::Result<usize> {
+            match INPUT.with(|input| input.borrow_mut().pop_front()) {
+                Some(line) => {
+                    buf.push_str(&line);
+                    Ok(line.len())
+                }
+                None => Ok(0),
+            }
+        }
+    }
+
+    #[test]
+    fn read_line_trimmed_strips_crlf_and_lf() {
+        set_input(&["one\r\n", "two\n", "three"]);
+        assert_eq!(TestIo::read_line_trimmed().unwrap(), "one");
+        assert_eq!(TestIo::read_line_trimmed().unwrap(), "two");
+        assert_eq!(TestIo::read_line_trimmed().unwrap(), "three");
+    }
+
+    #[test]
+    fn read_line_trimmed_leaves_a_lone_trailing_cr_alone() {
+        // A line ending in a literal `\r` with no following `\n` (as
+        // can happen at EOF) is not a CRLF terminator and must survive.
+        set_input(&["no-newline-here\r"]);
+        assert_eq!(TestIo::read_line_trimmed().unwrap(), "no-newline-here\r");
+    }
+
+    #[test]
+    fn lines_iterator_trims_each_line_and_stops_at_eof() {
+        set_input(&["a\r\n", "b\n", "c"]);
+        let collected: Vec<String> =
+            TestIo::lines().collect::<std::io::Result<_>>().unwrap();
+        assert_eq!(collected, vec!["a", "b", "c"]);
+    }
+
+    #[test]
+    fn lines_iterator_yields_nothing_on_immediate_eof() {
+        set_input(&[]);
+        let collected: Vec<String> =
+            TestIo::lines().collect::<std::io::Result<_>>().unwrap();
+        assert!(collected.is_empty());
+    }
+
+    #[test]
+    fn prompt_validated_accepts_a_valid_first_answer() {
+        set_input(&["42\n"]);
+        let value =
+            TestIo::prompt_validated("n: ", 3, |s| s.parse::<i32>()).unwrap();
+        assert_eq!(value, 42);
+    }
+
+    #[test]
+    fn prompt_validated_retries_on_invalid_input_until_valid() {
+        set_input(&["abc\n", "xyz\n", "7\n"]);
+        let value =
+            TestIo::prompt_validated("n: ", 3, |s| s.parse::<i32>()).unwrap();
+        assert_eq!(value, 7);
+    }
+
+    #[test]
+    fn prompt_validated_uses_up_to_exactly_max_attempts() {
+        // Exactly `max_attempts` invalid answers: the last one must
+        // still be consumed as an attempt, not left over.
+        set_input(&["a\n", "b\n", "c\n"]);
+        let err =
+            TestIo::prompt_validated("n: ", 3, |s| s.parse::<i32>()).unwrap_err();
+        assert!(matches!(err, PromptError::MaxAttempts(_)));
+        // No input should remain queued.
+        assert_eq!(TestIo::read_line(&mut String::new()).unwrap(), 0);
+    }
+
+    #[test]
+    fn prompt_validated_reports_eof_before_any_valid_answer() {
+        set_input(&[]);
+        let err =
+            TestIo::prompt_validated("n: ", 3, |s| s.parse::<i32>()).unwrap_err();
+        assert!(matches!(err, PromptError::Eof));
+    }
+
+    #[test]
+    fn prompt_parse_forwards_to_prompt_validated() {
+        set_input(&["not-a-number\n", "9\n"]);
+        let value = TestIo::prompt_parse::<i32>("n: ", 2).unwrap();
+        assert_eq!(value, 9);
+    }
+
+    #[test]
+    fn display_literal_fast_path_still_honors_a_print_override() {
+        // The zero-interpolation `display!`/`display_line!` arms route
+        // through `print_bytes`/`println_bytes`, whose defaults forward
+        // to `print`/`println` — so an `Io` like `TestIo` that
+        // overrides those to capture output, rather than touching real
+        // stdout, must see the literal here too.
+        take_output();
+        display!(TestIo, "hello").unwrap();
+        display_line!(TestIo, "world").unwrap();
+        assert_eq!(take_output(), vec!["hello".to_string(), "world".to_string()]);
+    }
+
+    #[test]
+    fn display_literal_with_braces_still_interpolates() {
+        // A literal containing `{...}` must not take the raw-bytes fast
+        // path: it needs to go through `format_args!` both so the braces
+        // are actually substituted and so a typo'd `{`/`}` is still
+        // caught at compile time.
+        let value = 42;
+        take_output();
+        display!(TestIo, "the answer is {value}").unwrap();
+        assert_eq!(take_output(), vec!["the answer is 42".to_string()]);
+    }
+
+    /// An in-memory writer `BufferedIo` can wrap instead of real stdout,
+    /// so its buffering behavior can be observed without redirecting the
+    /// process's actual stdout.
+    #[derive(Clone, Default)]
+    struct SharedBuf(std::rc::Rc<RefCell<Vec<u8>>>);
+
+    impl std::io::Write for SharedBuf {
+        fn write(&mut self, buf: &[u8]) -> std::io::Result<usize> {
+            self.0.borrow_mut().extend_from_slice(buf);
+            Ok(buf.len())
+        }
+
+        fn flush(&mut self) -> std::io::Result<()> {
+            Ok(())
+        }
+    }
+
+    #[test]
+    fn buffered_io_line_mode_flushes_after_each_newline() {
+        let shared = SharedBuf::default();
+        let mut io = BufferedIo::<TestIo, _>::with_writer(BufferMode::LineBuffered, shared.clone());
+        io.println("a").unwrap();
+        assert_eq!(*shared.0.borrow(), b"a\n");
+    }
+
+    #[test]
+    fn buffered_io_block_mode_holds_output_until_flush() {
+        let shared = SharedBuf::default();
+        let mut io =
+            BufferedIo::<TestIo, _>::with_writer(BufferMode::BlockBuffered, shared.clone());
+        io.print("a").unwrap();
+        io.write_bytes(b"b").unwrap();
+        assert!(shared.0.borrow().is_empty());
+        io.flush().unwrap();
+        assert_eq!(*shared.0.borrow(), b"ab");
+    }
+
+    #[test]
+    fn buffered_io_flushes_on_drop() {
+        let shared = SharedBuf::default();
+        {
+            let mut io =
+                BufferedIo::<TestIo, _>::with_writer(BufferMode::BlockBuffered, shared.clone());
+            io.println("bye").unwrap();
+            assert!(shared.0.borrow().is_empty());
+        }
+        assert_eq!(*shared.0.borrow(), b"bye\n");
+    }
+}